@@ -0,0 +1,224 @@
+//! A synchronous, [`ureq`](https://docs.rs/ureq)-backed translation client for
+//! use outside async runtimes. The API mirrors the crate root, minus the
+//! `.await`:
+//!
+//! ```rust,no_run
+//! use libretranslate::blocking::Translator;
+//! use libretranslate::Language;
+//!
+//! fn main() {
+//!     let input = "Olá Mundo!";
+//!     let source = Language::Portuguese;
+//!     let target = Language::English;
+//!
+//!     match Translator::translate(Some(source), target, input) {
+//!         Ok(data) => println!("{}: {}\n{}: {}", data.source.pretty(), data.input, data.target.pretty(), data.output),
+//!         Err(error) => panic!("{}", error),
+//!     };
+//! }
+//! ```
+
+use serde_json::Value;
+
+use crate::{Language, TranslateError};
+
+pub struct Translator {
+    pub source: Language,
+    pub target: Language,
+    pub input: String,
+    pub output: String,
+    /// Confidence of the local language detector, when `source` was guessed
+    /// rather than given by the caller. `None` if `source` was passed in
+    /// explicitly. Short inputs tend to detect poorly, so check this before
+    /// trusting the guess.
+    pub confidence: Option<f64>,
+}
+
+impl Translator {
+    /// Detect the language of `input` locally with `whatlang`, without making
+    /// a network request. Returns the detected `Language` along with the
+    /// detector's confidence (0.0 to 1.0).
+    pub fn detect(input: &str) -> Result<(Language, f64), TranslateError> {
+        crate::detect(input)
+    }
+
+    /// Translate text between two languages using the official
+    /// `https://libretranslate.com` instance. Pass `None` as `source` to
+    /// detect the input's language locally instead of specifying it.
+    ///
+    /// To target a self-hosted instance or attach an API key, use
+    /// [`TranslatorBuilder`] instead.
+    pub fn translate(source: Option<Language>, target: Language, input: &str) -> Result<Self, TranslateError> {
+        TranslatorBuilder::new().translate(source, target, input)
+    }
+
+    /// Translate many strings in a single request against the official
+    /// `https://libretranslate.com` instance, preserving input order. Pass
+    /// `None` as `source` to detect the batch's language locally instead of
+    /// specifying it.
+    ///
+    /// This is far cheaper than calling [`translate`](Translator::translate)
+    /// in a loop, since all inputs are sent in one POST.
+    pub fn translate_batch(source: Option<Language>, target: Language, inputs: &[&str]) -> Result<Vec<Self>, TranslateError> {
+        TranslatorBuilder::new().translate_batch(source, target, inputs)
+    }
+}
+
+/// Builds a [`Translator`] request against a custom LibreTranslate instance,
+/// optionally authenticated with an API key.
+///
+/// ```rust,no_run
+/// use libretranslate::blocking::TranslatorBuilder;
+/// use libretranslate::Language;
+///
+/// let translator = TranslatorBuilder::new()
+///     .base_url("https://libretranslate.example.com")
+///     .api_key("my-api-key")
+///     .translate(Some(Language::Portuguese), Language::English, "Olá Mundo!");
+/// ```
+pub struct TranslatorBuilder {
+    base_url: String,
+    api_key: Option<String>,
+}
+
+impl TranslatorBuilder {
+    /// Create a builder targeting the official `https://libretranslate.com` instance with no API key.
+    pub fn new() -> Self {
+        Self {
+            base_url: String::from("https://libretranslate.com"),
+            api_key: None,
+        }
+    }
+
+    /// Set the base URL of the LibreTranslate instance to request from (ex. "https://libretranslate.example.com").
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Attach an API key, sent as `api_key` in the request body.
+    pub fn api_key(mut self, api_key: &str) -> Self {
+        self.api_key = Some(api_key.to_string());
+        self
+    }
+
+    fn body(&self, source: Language, target: Language, q: Value) -> Value {
+        let mut body = serde_json::json!({
+            "q": q,
+            "source": source.code(),
+            "target": target.code(),
+        });
+
+        if let Some(api_key) = &self.api_key {
+            body["api_key"] = Value::String(api_key.clone());
+        }
+
+        body
+    }
+
+    /// Translate text between two languages against the configured instance.
+    /// Pass `None` as `source` to detect the input's language locally instead
+    /// of specifying it.
+    pub fn translate(&self, source: Option<Language>, target: Language, input: &str) -> Result<Translator, TranslateError> {
+        let (source, confidence) = match source {
+            Some(source) => (source, None),
+            None => {
+                let (source, confidence) = Translator::detect(input)?;
+                (source, Some(confidence))
+            }
+        };
+
+        let body = self.body(source, target, Value::String(input.to_string()));
+        let url = format!("{}/translate", self.base_url);
+
+        match ureq::post(&url).send_json(body) {
+            Ok(data) => {
+                let string: String = match data.into_string() {
+                    Ok(data) => data,
+                    Err(error) => {
+                        return Err(TranslateError::ParseError(error.to_string()));
+                    }
+                };
+
+                let parsed_json: Value = match serde_json::from_str(&string) {
+                    Ok(parsed_json) => parsed_json,
+                    Err(error) => {
+                        return Err(TranslateError::ParseError(error.to_string()));
+                    }
+                };
+
+                let output = crate::parse_output(&parsed_json)?;
+                let input = input.to_string();
+
+                let myself = Translator {
+                    source,
+                    target,
+                    input,
+                    output,
+                    confidence,
+                };
+
+                Ok(myself)
+            }
+            Err(error) => Err(TranslateError::HttpError(error.to_string())),
+        }
+    }
+
+    /// Translate many strings in a single request against the configured
+    /// instance, preserving input order. Pass `None` as `source` to detect
+    /// the batch's language locally instead of specifying it.
+    pub fn translate_batch(&self, source: Option<Language>, target: Language, inputs: &[&str]) -> Result<Vec<Translator>, TranslateError> {
+        let (source, confidence) = match source {
+            Some(source) => (source, None),
+            None => {
+                let joined = inputs.join(" ");
+                let (source, confidence) = Translator::detect(&joined)?;
+                (source, Some(confidence))
+            }
+        };
+
+        let body = self.body(source, target, serde_json::json!(inputs));
+        let url = format!("{}/translate", self.base_url);
+
+        match ureq::post(&url).send_json(body) {
+            Ok(data) => {
+                let string: String = match data.into_string() {
+                    Ok(data) => data,
+                    Err(error) => {
+                        return Err(TranslateError::ParseError(error.to_string()));
+                    }
+                };
+
+                let parsed_json: Value = match serde_json::from_str(&string) {
+                    Ok(parsed_json) => parsed_json,
+                    Err(error) => {
+                        return Err(TranslateError::ParseError(error.to_string()));
+                    }
+                };
+
+                let outputs = crate::parse_outputs(&parsed_json, inputs.len())?;
+
+                let translators = inputs
+                    .iter()
+                    .zip(outputs)
+                    .map(|(input, output)| Translator {
+                        source,
+                        target,
+                        input: input.to_string(),
+                        output,
+                        confidence,
+                    })
+                    .collect();
+
+                Ok(translators)
+            }
+            Err(error) => Err(TranslateError::HttpError(error.to_string())),
+        }
+    }
+}
+
+impl Default for TranslatorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,91 @@
+//! A small i18n layer for localizing an application's own UI strings,
+//! built on top of the [`blocking`](crate::blocking) client.
+//!
+//! ```rust,no_run
+//! use libretranslate::catalog::Catalog;
+//! use libretranslate::Language;
+//!
+//! fn main() {
+//!     let catalog = Catalog::load("catalog.json", Language::English).unwrap();
+//!     println!("{}", catalog.tr("greeting", Language::German));
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+
+use once_cell::sync::OnceCell;
+
+use crate::blocking::Translator;
+use crate::{Language, TranslateError};
+
+/// A table of an application's source UI strings, keyed by message, loaded
+/// from a JSON file:
+///
+/// ```json
+/// {
+///     "greeting": "Hello, world!",
+///     "farewell": "Goodbye!"
+/// }
+/// ```
+///
+/// Each entry is translated through the existing [`blocking`](crate::blocking)
+/// request path the first time it's looked up for a given target language.
+/// A successful translation is cached in memory, so later lookups of it never
+/// hit the network again; a failed translation is not cached, and the next
+/// lookup of that entry requests it again.
+pub struct Catalog {
+    source: Language,
+    entries: HashMap<String, String>,
+    cache: OnceCell<Mutex<HashMap<(Language, String), String>>>,
+}
+
+impl Catalog {
+    /// Load a catalog of source strings from a JSON file keyed by message.
+    /// `source` is the language the catalog's strings are already written
+    /// in — since these strings are short, known-ahead-of-time UI labels
+    /// (not arbitrary user text), detecting `source` locally would be
+    /// unreliable, so it must be declared.
+    pub fn load(path: impl AsRef<Path>, source: Language) -> Result<Self, TranslateError> {
+        let data = fs::read_to_string(path).map_err(|error| TranslateError::ParseError(error.to_string()))?;
+        let entries: HashMap<String, String> =
+            serde_json::from_str(&data).map_err(|error| TranslateError::ParseError(error.to_string()))?;
+
+        Ok(Self {
+            source,
+            entries,
+            cache: OnceCell::new(),
+        })
+    }
+
+    fn cache(&self) -> &Mutex<HashMap<(Language, String), String>> {
+        self.cache.get_or_init(|| Mutex::new(HashMap::new()))
+    }
+
+    /// Return the translation of `key` into `target`, translating and
+    /// caching it on first use. Falls back to the untranslated source string
+    /// if translation fails, or to `key` itself if `key` isn't in the
+    /// catalog.
+    pub fn tr(&self, key: &str, target: Language) -> String {
+        let source_text = match self.entries.get(key) {
+            Some(source_text) => source_text,
+            None => return key.to_string(),
+        };
+
+        let cache_key = (target, source_text.clone());
+
+        if let Some(cached) = self.cache().lock().unwrap().get(&cache_key) {
+            return cached.clone();
+        }
+
+        match Translator::translate(Some(self.source), target, source_text) {
+            Ok(translator) => {
+                self.cache().lock().unwrap().insert(cache_key, translator.output.clone());
+                translator.output
+            }
+            Err(_) => source_text.clone(),
+        }
+    }
+}
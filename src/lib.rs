@@ -1,5 +1,5 @@
 //! A LibreTranslate API for Rust.
-//! ```
+//! ```toml
 //! libretranslate = "0.1.6"
 //! ```
 //!
@@ -7,15 +7,16 @@
 //!
 //! Using it is fairly simple:
 //!
-//! ```rust
+//! ```rust,no_run
 //! use libretranslate::{Translator, Language};
 //!
-//! fn main() {
+//! #[tokio::main]
+//! async fn main() {
 //!     let input = "Olá Mundo!";
 //!     let source = Language::Portuguese;
 //!     let target = Language::English;
 //!
-//!     match Translator::translate(source, target, input) {
+//!     match Translator::translate(Some(source), target, input).await {
 //!         Ok(data) => println!("{}: {}\n{}: {}", data.source.pretty(), data.input, data.target.pretty(), data.output),
 //!         Err(error) => panic!("{}", error),
 //!     };
@@ -23,42 +24,139 @@
 //! ```
 //!
 //! Output:
-//! ```
+//! ```text
 //! Portuguese: Olá Mundo!
 //! English: Hello world!
 //! ```
 //!
+//! If you don't know the source language ahead of time, pass `None` and
+//! libretranslate-rs will detect it locally with `whatlang` before sending
+//! the request:
+//!
+//! ```rust,no_run
+//! use libretranslate::{Translator, Language};
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let input = "Olá Mundo!";
+//!     let target = Language::English;
+//!
+//!     match Translator::translate(None, target, input).await {
+//!         Ok(data) => println!("{}: {}\n{}: {}", data.source.pretty(), data.input, data.target.pretty(), data.output),
+//!         Err(error) => panic!("{}", error),
+//!     };
+//! }
+//! ```
+//!
+//! If your application isn't built on an async runtime, use the
+//! [`blocking`] module instead, which exposes the same API built on `ureq`.
+//!
 //! Written with love, in Rust by Grant Handy.
 
+use once_cell::sync::OnceCell;
 use serde_json::Value;
+use whatlang::Lang;
+
+pub mod blocking;
+pub mod catalog;
 
 /// Languages that can used for input and output of the ['translate'] function.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Language {
     English,
     Arabic,
+    Azerbaijani,
     Chinese,
+    Czech,
+    Danish,
+    Dutch,
+    Finnish,
     French,
     German,
+    Greek,
+    Hebrew,
+    Hindi,
+    Hungarian,
+    Indonesian,
     Italian,
+    Japanese,
+    Korean,
+    Persian,
+    Polish,
     Portuguese,
-    Russain,
+    Russian,
+    Slovak,
     Spanish,
+    Swedish,
+    Turkish,
+    Ukrainian,
+    Vietnamese,
 }
 
+/// Every supported `Language`, used to drive [`Language::from_code`].
+const ALL_LANGUAGES: &[Language] = &[
+    Language::English,
+    Language::Arabic,
+    Language::Azerbaijani,
+    Language::Chinese,
+    Language::Czech,
+    Language::Danish,
+    Language::Dutch,
+    Language::Finnish,
+    Language::French,
+    Language::German,
+    Language::Greek,
+    Language::Hebrew,
+    Language::Hindi,
+    Language::Hungarian,
+    Language::Indonesian,
+    Language::Italian,
+    Language::Japanese,
+    Language::Korean,
+    Language::Persian,
+    Language::Polish,
+    Language::Portuguese,
+    Language::Russian,
+    Language::Slovak,
+    Language::Spanish,
+    Language::Swedish,
+    Language::Turkish,
+    Language::Ukrainian,
+    Language::Vietnamese,
+];
+
 impl Language {
     /// Return the language with the language code name. (ex. "ar", "de")
     pub fn code(&self) -> &str {
         match self {
             Language::English => "en",
             Language::Arabic => "ar",
+            Language::Azerbaijani => "az",
             Language::Chinese => "zh",
+            Language::Czech => "cs",
+            Language::Danish => "da",
+            Language::Dutch => "nl",
+            Language::Finnish => "fi",
             Language::French => "fr",
             Language::German => "de",
+            Language::Greek => "el",
+            Language::Hebrew => "he",
+            Language::Hindi => "hi",
+            Language::Hungarian => "hu",
+            Language::Indonesian => "id",
             Language::Italian => "it",
+            Language::Japanese => "ja",
+            Language::Korean => "ko",
+            Language::Persian => "fa",
+            Language::Polish => "pl",
             Language::Portuguese => "pt",
-            Language::Russain => "rs",
+            Language::Russian => "ru",
+            Language::Slovak => "sk",
             Language::Spanish => "es",
+            Language::Swedish => "sv",
+            Language::Turkish => "tr",
+            Language::Ukrainian => "uk",
+            Language::Vietnamese => "vi",
         }
     }
 
@@ -67,30 +165,97 @@ impl Language {
         match self {
             Language::English => "English",
             Language::Arabic => "Arabic",
+            Language::Azerbaijani => "Azerbaijani",
             Language::Chinese => "Chinese",
+            Language::Czech => "Czech",
+            Language::Danish => "Danish",
+            Language::Dutch => "Dutch",
+            Language::Finnish => "Finnish",
             Language::French => "French",
             Language::German => "German",
+            Language::Greek => "Greek",
+            Language::Hebrew => "Hebrew",
+            Language::Hindi => "Hindi",
+            Language::Hungarian => "Hungarian",
+            Language::Indonesian => "Indonesian",
             Language::Italian => "Italian",
+            Language::Japanese => "Japanese",
+            Language::Korean => "Korean",
+            Language::Persian => "Persian",
+            Language::Polish => "Polish",
             Language::Portuguese => "Portuguese",
-            Language::Russain => "Russain",
+            Language::Russian => "Russian",
+            Language::Slovak => "Slovak",
             Language::Spanish => "Spanish",
+            Language::Swedish => "Swedish",
+            Language::Turkish => "Turkish",
+            Language::Ukrainian => "Ukrainian",
+            Language::Vietnamese => "Vietnamese",
+        }
+    }
+
+    /// Parse a `Language` from either an ISO code ("de") or a pretty name
+    /// ("German"), case-insensitively. Returns `None` for codes/names we
+    /// don't support.
+    pub fn from_code(value: &str) -> Option<Self> {
+        let value = value.trim();
+
+        ALL_LANGUAGES.iter().copied().find(|language| {
+            language.code().eq_ignore_ascii_case(value) || language.pretty().eq_ignore_ascii_case(value)
+        })
+    }
+
+    /// Map a language identified by `whatlang`'s script/trigram detector to a
+    /// `Language` we can actually request a translation for.
+    fn from_whatlang(lang: Lang) -> Option<Self> {
+        match lang {
+            Lang::Eng => Some(Language::English),
+            Lang::Ara => Some(Language::Arabic),
+            Lang::Aze => Some(Language::Azerbaijani),
+            Lang::Cmn => Some(Language::Chinese),
+            Lang::Ces => Some(Language::Czech),
+            Lang::Dan => Some(Language::Danish),
+            Lang::Nld => Some(Language::Dutch),
+            Lang::Fin => Some(Language::Finnish),
+            Lang::Fra => Some(Language::French),
+            Lang::Deu => Some(Language::German),
+            Lang::Ell => Some(Language::Greek),
+            Lang::Heb => Some(Language::Hebrew),
+            Lang::Hin => Some(Language::Hindi),
+            Lang::Hun => Some(Language::Hungarian),
+            Lang::Ind => Some(Language::Indonesian),
+            Lang::Ita => Some(Language::Italian),
+            Lang::Jpn => Some(Language::Japanese),
+            Lang::Kor => Some(Language::Korean),
+            Lang::Pes => Some(Language::Persian),
+            Lang::Pol => Some(Language::Polish),
+            Lang::Por => Some(Language::Portuguese),
+            Lang::Rus => Some(Language::Russian),
+            Lang::Slk => Some(Language::Slovak),
+            Lang::Spa => Some(Language::Spanish),
+            Lang::Swe => Some(Language::Swedish),
+            Lang::Tur => Some(Language::Turkish),
+            Lang::Ukr => Some(Language::Ukrainian),
+            Lang::Vie => Some(Language::Vietnamese),
+            _ => None,
         }
     }
 }
 
+impl std::str::FromStr for Language {
+    type Err = TranslateError;
+
+    /// Parse a `Language` from either an ISO code ("de") or a pretty name ("German"), case-insensitively.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Language::from_code(value).ok_or_else(|| {
+            TranslateError::ParseError(format!("'{}' is not a supported language code or name", value))
+        })
+    }
+}
+
 impl std::fmt::Display for Language {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            Language::English => write!(f, "en"),
-            Language::Arabic => write!(f, "ar"),
-            Language::Chinese => write!(f, "zh"),
-            Language::French => write!(f, "fr"),
-            Language::German => write!(f, "de"),
-            Language::Italian => write!(f, "it"),
-            Language::Portuguese => write!(f, "pt"),
-            Language::Russain => write!(f, "rs"),
-            Language::Spanish => write!(f, "es"),
-        }
+        write!(f, "{}", self.code())
     }
 }
 
@@ -99,6 +264,7 @@ impl std::fmt::Display for Language {
 pub enum TranslateError {
     HttpError(String),
     ParseError(String),
+    DetectError(String),
 }
 
 impl std::error::Error for TranslateError {}
@@ -107,67 +273,352 @@ impl std::fmt::Display for TranslateError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             TranslateError::HttpError(error) => {
-                write!(f, "HTTP Request Error: {}", error.to_string())
+                write!(f, "HTTP Request Error: {}", error)
             }
             TranslateError::ParseError(error) => {
-                write!(f, "JSON Parsing Error: {}", error.to_string())
+                write!(f, "JSON Parsing Error: {}", error)
+            }
+            TranslateError::DetectError(error) => {
+                write!(f, "Language Detection Error: {}", error)
             }
         }
     }
 }
 
+/// Detect the language of `input` locally with `whatlang`, without making a
+/// network request. Returns the detected `Language` along with the
+/// detector's confidence (0.0 to 1.0). Shared by the async and
+/// [`blocking`] clients.
+pub(crate) fn detect(input: &str) -> Result<(Language, f64), TranslateError> {
+    let info = match whatlang::detect(input) {
+        Some(info) => info,
+        None => {
+            return Err(TranslateError::DetectError(String::from(
+                "Unable to detect a language in the given input",
+            )))
+        }
+    };
+
+    match Language::from_whatlang(info.lang()) {
+        Some(language) => Ok((language, info.confidence())),
+        None => Err(TranslateError::DetectError(format!(
+            "Detected language '{}' is not supported",
+            info.lang().name()
+        ))),
+    }
+}
+
+/// Pull the single translated string out of a `/translate` response body.
+/// Shared by the async and [`blocking`] clients.
+pub(crate) fn parse_output(parsed_json: &Value) -> Result<String, TranslateError> {
+    match &parsed_json["translatedText"] {
+        Value::String(output) => Ok(output.to_string()),
+        _ => Err(TranslateError::ParseError(String::from(
+            "Unable to find translatedText in parsed JSON",
+        ))),
+    }
+}
+
+/// Pull the batch of translated strings out of a `/translate` response body,
+/// checking that it has exactly `expected_len` entries in order. Shared by
+/// the async and [`blocking`] clients.
+pub(crate) fn parse_outputs(parsed_json: &Value, expected_len: usize) -> Result<Vec<String>, TranslateError> {
+    let outputs = match &parsed_json["translatedText"] {
+        Value::Array(outputs) => outputs,
+        _ => {
+            return Err(TranslateError::ParseError(String::from(
+                "Unable to find translatedText array in parsed JSON",
+            )))
+        }
+    };
+
+    if outputs.len() != expected_len {
+        return Err(TranslateError::ParseError(String::from(
+            "translatedText array length doesn't match the number of inputs",
+        )));
+    }
+
+    outputs
+        .iter()
+        .map(|output| match output {
+            Value::String(output) => Ok(output.to_string()),
+            _ => Err(TranslateError::ParseError(String::from(
+                "Unable to find translatedText in parsed JSON",
+            ))),
+        })
+        .collect()
+}
+
+static HTTP_CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
+
+/// The shared `reqwest::Client` used by every [`TranslatorBuilder`], so
+/// requests reuse connection pooling/keep-alive instead of paying a fresh
+/// handshake per call.
+fn http_client() -> &'static reqwest::Client {
+    HTTP_CLIENT.get_or_init(reqwest::Client::new)
+}
+
 pub struct Translator {
     pub source: Language,
     pub target: Language,
     pub input: String,
     pub output: String,
+    /// Confidence of the local language detector, when `source` was guessed
+    /// rather than given by the caller. `None` if `source` was passed in
+    /// explicitly. Short inputs tend to detect poorly, so check this before
+    /// trusting the guess.
+    pub confidence: Option<f64>,
 }
 
 impl Translator {
-    /// Translate text between two languages.
-    pub fn translate(source: Language, target: Language, input: &str) -> Result<Self, TranslateError> {
-        match ureq::post("https://libretranslate.com/translate").send_json(ureq::json!({
-            "q": input,
+    /// Detect the language of `input` locally with `whatlang`, without making
+    /// a network request. Returns the detected `Language` along with the
+    /// detector's confidence (0.0 to 1.0).
+    pub fn detect(input: &str) -> Result<(Language, f64), TranslateError> {
+        detect(input)
+    }
+
+    /// Translate text between two languages using the official
+    /// `https://libretranslate.com` instance. Pass `None` as `source` to
+    /// detect the input's language locally instead of specifying it.
+    ///
+    /// To target a self-hosted instance or attach an API key, use
+    /// [`TranslatorBuilder`] instead.
+    pub async fn translate(source: Option<Language>, target: Language, input: &str) -> Result<Self, TranslateError> {
+        TranslatorBuilder::new().translate(source, target, input).await
+    }
+
+    /// Translate many strings in a single request against the official
+    /// `https://libretranslate.com` instance, preserving input order. Pass
+    /// `None` as `source` to detect the batch's language locally instead of
+    /// specifying it.
+    ///
+    /// This is far cheaper than calling [`translate`](Translator::translate)
+    /// in a loop, since all inputs are sent in one POST.
+    pub async fn translate_batch(source: Option<Language>, target: Language, inputs: &[&str]) -> Result<Vec<Self>, TranslateError> {
+        TranslatorBuilder::new().translate_batch(source, target, inputs).await
+    }
+}
+
+/// Builds a [`Translator`] request against a custom LibreTranslate instance,
+/// optionally authenticated with an API key.
+///
+/// ```rust
+/// use libretranslate::{Language, TranslatorBuilder};
+///
+/// # async fn run() {
+/// let translator = TranslatorBuilder::new()
+///     .base_url("https://libretranslate.example.com")
+///     .api_key("my-api-key")
+///     .translate(Some(Language::Portuguese), Language::English, "Olá Mundo!")
+///     .await;
+/// # }
+/// ```
+pub struct TranslatorBuilder {
+    base_url: String,
+    api_key: Option<String>,
+}
+
+impl TranslatorBuilder {
+    /// Create a builder targeting the official `https://libretranslate.com` instance with no API key.
+    pub fn new() -> Self {
+        Self {
+            base_url: String::from("https://libretranslate.com"),
+            api_key: None,
+        }
+    }
+
+    /// Set the base URL of the LibreTranslate instance to request from (ex. "https://libretranslate.example.com").
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Attach an API key, sent as `api_key` in the request body.
+    pub fn api_key(mut self, api_key: &str) -> Self {
+        self.api_key = Some(api_key.to_string());
+        self
+    }
+
+    fn body(&self, source: Language, target: Language, q: Value) -> Value {
+        let mut body = serde_json::json!({
+            "q": q,
             "source": source.code(),
             "target": target.code(),
-        })) {
-            Ok(data) => {
-                let string: String = match data.into_string() {
-                    Ok(data) => data,
-                    Err(error) => {
-                        return Err(TranslateError::ParseError(error.to_string()));
-                    }
-                };
-
-                let parsed_json: Value = match serde_json::from_str(&string) {
-                    Ok(parsed_json) => parsed_json,
-                    Err(error) => {
-                        return Err(TranslateError::ParseError(error.to_string()));
-                    }
-                };
-
-                let output = match &parsed_json["translatedText"] {
-                    Value::String(output) => output,
-                    _ => {
-                        return Err(TranslateError::ParseError(String::from(
-                            "Unable to find translatedText in parsed JSON",
-                        )))
-                    }
-                };
-
-                let input = input.to_string();
-                let output = output.to_string();
-
-                let myself = Self {
-                    source,
-                    target,
-                    input,
-                    output,
-                };
-
-                return Ok(myself);
+        });
+
+        if let Some(api_key) = &self.api_key {
+            body["api_key"] = Value::String(api_key.clone());
+        }
+
+        body
+    }
+
+    /// Translate text between two languages against the configured instance.
+    /// Pass `None` as `source` to detect the input's language locally instead
+    /// of specifying it.
+    pub async fn translate(&self, source: Option<Language>, target: Language, input: &str) -> Result<Translator, TranslateError> {
+        let (source, confidence) = match source {
+            Some(source) => (source, None),
+            None => {
+                let (source, confidence) = detect(input)?;
+                (source, Some(confidence))
             }
+        };
+
+        let body = self.body(source, target, Value::String(input.to_string()));
+        let url = format!("{}/translate", self.base_url);
+
+        let response = match http_client().post(&url).json(&body).send().await {
+            Ok(response) => response,
             Err(error) => return Err(TranslateError::HttpError(error.to_string())),
         };
+
+        let string = match response.text().await {
+            Ok(string) => string,
+            Err(error) => return Err(TranslateError::ParseError(error.to_string())),
+        };
+
+        let parsed_json: Value = match serde_json::from_str(&string) {
+            Ok(parsed_json) => parsed_json,
+            Err(error) => return Err(TranslateError::ParseError(error.to_string())),
+        };
+
+        let output = parse_output(&parsed_json)?;
+        let input = input.to_string();
+
+        Ok(Translator {
+            source,
+            target,
+            input,
+            output,
+            confidence,
+        })
+    }
+
+    /// Translate many strings in a single request against the configured
+    /// instance, preserving input order. Pass `None` as `source` to detect
+    /// the batch's language locally instead of specifying it.
+    pub async fn translate_batch(&self, source: Option<Language>, target: Language, inputs: &[&str]) -> Result<Vec<Translator>, TranslateError> {
+        let (source, confidence) = match source {
+            Some(source) => (source, None),
+            None => {
+                let joined = inputs.join(" ");
+                let (source, confidence) = detect(&joined)?;
+                (source, Some(confidence))
+            }
+        };
+
+        let body = self.body(source, target, serde_json::json!(inputs));
+        let url = format!("{}/translate", self.base_url);
+
+        let response = match http_client().post(&url).json(&body).send().await {
+            Ok(response) => response,
+            Err(error) => return Err(TranslateError::HttpError(error.to_string())),
+        };
+
+        let string = match response.text().await {
+            Ok(string) => string,
+            Err(error) => return Err(TranslateError::ParseError(error.to_string())),
+        };
+
+        let parsed_json: Value = match serde_json::from_str(&string) {
+            Ok(parsed_json) => parsed_json,
+            Err(error) => return Err(TranslateError::ParseError(error.to_string())),
+        };
+
+        let outputs = parse_outputs(&parsed_json, inputs.len())?;
+
+        let translators = inputs
+            .iter()
+            .zip(outputs)
+            .map(|(input, output)| Translator {
+                source,
+                target,
+                input: input.to_string(),
+                output,
+                confidence,
+            })
+            .collect();
+
+        Ok(translators)
+    }
+}
+
+impl Default for TranslatorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_code_round_trips_codes_and_pretty_names() {
+        for language in ALL_LANGUAGES {
+            assert_eq!(Language::from_code(language.code()).unwrap(), *language);
+            assert_eq!(Language::from_code(language.pretty()).unwrap(), *language);
+        }
+    }
+
+    #[test]
+    fn from_code_is_case_insensitive() {
+        assert_eq!(Language::from_code("DE").unwrap(), Language::German);
+        assert_eq!(Language::from_code("german").unwrap(), Language::German);
+    }
+
+    #[test]
+    fn from_code_rejects_unknown_input() {
+        assert!(Language::from_code("not-a-language").is_none());
+    }
+
+    #[test]
+    fn from_str_mirrors_from_code() {
+        assert_eq!("fr".parse::<Language>().unwrap(), Language::French);
+        assert!("not-a-language".parse::<Language>().is_err());
+    }
+
+    #[test]
+    fn from_whatlang_maps_known_languages() {
+        assert_eq!(Language::from_whatlang(Lang::Deu), Some(Language::German));
+        assert_eq!(Language::from_whatlang(Lang::Jpn), Some(Language::Japanese));
+    }
+
+    #[test]
+    fn from_whatlang_rejects_unsupported_languages() {
+        assert_eq!(Language::from_whatlang(Lang::Zul), None);
+    }
+
+    #[test]
+    fn parse_outputs_rejects_length_mismatch() {
+        let parsed_json = serde_json::json!({ "translatedText": ["only one"] });
+
+        let error = parse_outputs(&parsed_json, 2).unwrap_err();
+        assert!(matches!(error, TranslateError::ParseError(_)));
+    }
+
+    #[test]
+    fn parse_outputs_rejects_non_array() {
+        let parsed_json = serde_json::json!({ "translatedText": "not an array" });
+
+        let error = parse_outputs(&parsed_json, 1).unwrap_err();
+        assert!(matches!(error, TranslateError::ParseError(_)));
+    }
+
+    #[test]
+    fn parse_outputs_returns_strings_in_order() {
+        let parsed_json = serde_json::json!({ "translatedText": ["one", "two"] });
+
+        assert_eq!(parse_outputs(&parsed_json, 2).unwrap(), vec!["one", "two"]);
+    }
+
+    #[test]
+    fn parse_output_rejects_missing_field() {
+        let parsed_json = serde_json::json!({ "notTranslatedText": "oops" });
+
+        let error = parse_output(&parsed_json).unwrap_err();
+        assert!(matches!(error, TranslateError::ParseError(_)));
     }
 }